@@ -18,7 +18,7 @@
 
 use sp_core::{
 	ecdsa::{Pair, Public, Signature},
-	Pair as PairT,
+	keccak_256, Pair as PairT,
 };
 
 /// Set of test accounts using ECDSA crypto
@@ -39,6 +39,13 @@ pub enum Keyring {
 	Ferdie,
 	One,
 	Two,
+	/// An arbitrary numbered account, deriving from the `//{n}` seed.
+	///
+	/// Unlike the named accounts this variant is not yielded by [`iter()`](Keyring::iter)
+	/// so that test authority sets can be grown beyond the eight fixed accounts without
+	/// perturbing code that enumerates the well-known ones.
+	#[strum(disabled)]
+	Custom(u128),
 }
 
 impl Keyring {
@@ -57,15 +64,74 @@ impl Keyring {
 		self.pair().public()
 	}
 
+	/// Sign the 32-byte `digest` directly, returning a recoverable `(v, r, s)` signature.
+	///
+	/// Used for Ethereum-style verification where the signer commits to a pre-computed hash
+	/// rather than to the raw message bytes.
+	pub fn sign_prehashed(self, digest: &[u8; 32]) -> Signature {
+		Pair::from(self).sign_prehashed(digest)
+	}
+
+	/// Sign `keccak_256(msg)`, returning a 65-byte recoverable signature.
+	///
+	/// This is the signature shape an on-chain Solidity `ecrecover` verifier expects for BEEFY
+	/// commitments consumed by an Ethereum light-client bridge.
+	pub fn sign_keccak256(self, msg: &[u8]) -> Signature {
+		self.sign_prehashed(&keccak_256(msg))
+	}
+
+	/// Recover the signer of a [`sign_keccak256`](Keyring::sign_keccak256) signature over `msg`.
+	pub fn recover(msg: &[u8], sig: &Signature) -> Option<Public> {
+		sig.recover_prehashed(&keccak_256(msg))
+	}
+
+	/// Serialize this account into the `sc-keystore` on-disk JSON representation.
+	///
+	/// A node's keystore stores each secret as a file whose whole contents are a JSON string of the
+	/// secret URI. Emitting exactly that shape lets a test write the blob to disk and have a real
+	/// node load it, so a validator can be booted from an on-disk key file rather than from the
+	/// in-memory enum. See [`keystore`] for the format.
+	pub fn to_keystore_json(self) -> String {
+		keystore::serialize(&self.to_seed())
+	}
+
+	/// Recover a pair from an `sc-keystore` JSON blob produced by
+	/// [`to_keystore_json`](Keyring::to_keystore_json).
+	pub fn from_keystore_json(json: &str) -> Option<Pair> {
+		let suri = keystore::deserialize(json)?;
+		Pair::from_string(&suri, None).ok()
+	}
+
+	/// Return a numbered test account deriving from the `//{n}` seed.
+	pub fn numeric(n: u128) -> Keyring {
+		Keyring::Custom(n)
+	}
+
 	/// Return seed string.
 	pub fn to_seed(self) -> String {
-		format!("//{}", self)
+		match self {
+			Keyring::Custom(n) => format!("//{}", n),
+			_ => format!("//{}", self),
+		}
 	}
 
-	/// Iterator over all test accounts
+	/// Iterator over all named test accounts
 	pub fn iter() -> impl (Iterator<Item = Keyring>) {
 		<Self as strum::IntoEnumIterator>::iter()
 	}
+
+	/// Look up the named account owning `who`.
+	///
+	/// Only the named accounts yielded by [`iter()`](Keyring::iter) are considered, so a
+	/// recovered key belonging to a [`Custom`](Keyring::Custom) account will not be matched.
+	pub fn from_public(who: &Public) -> Option<Keyring> {
+		Self::iter().find(|k| &k.public() == who)
+	}
+
+	/// Look up the named account deriving from `seed`.
+	pub fn from_seed(seed: &str) -> Option<Keyring> {
+		Self::iter().find(|k| k.to_seed() == seed)
+	}
 }
 
 impl From<Keyring> for Pair {
@@ -74,6 +140,120 @@ impl From<Keyring> for Pair {
 	}
 }
 
+/// Generate a per-scheme test keyring module named `$scheme`, backed by `sp_core::$scheme`.
+///
+/// The named accounts and `//Alice`-style seeds are identical across schemes, so the same account
+/// yields a deterministic key per curve. Keeping the body in one macro means the per-scheme
+/// keyrings cannot drift from one another, while still exposing a plain `ed25519::Keyring` /
+/// `sr25519::Keyring` that cross-crypto negotiation paths can use without a separate fixture crate.
+macro_rules! scheme_keyring {
+	($(#[$attr:meta])* $scheme:ident) => {
+		$(#[$attr])*
+		pub mod $scheme {
+			use sp_core::{
+				$scheme::{Pair, Public, Signature},
+				Pair as PairT,
+			};
+
+			#[allow(missing_docs)]
+			#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display, strum::EnumIter)]
+			pub enum Keyring {
+				Alice,
+				Bob,
+				Charlie,
+				Dave,
+				Eve,
+				Ferdie,
+				One,
+				Two,
+				/// An arbitrary numbered account, deriving from the `//{n}` seed.
+				#[strum(disabled)]
+				Custom(u128),
+			}
+
+			impl Keyring {
+				/// Sign `msg`.
+				pub fn sign(self, msg: &[u8]) -> Signature {
+					Pair::from(self).sign(msg)
+				}
+
+				/// Return key pair.
+				pub fn pair(self) -> Pair {
+					Pair::from_string(self.to_seed().as_str(), None).expect("static values are known good; qed")
+				}
+
+				/// Return public key.
+				pub fn public(self) -> Public {
+					self.pair().public()
+				}
+
+				/// Return a numbered test account deriving from the `//{n}` seed.
+				pub fn numeric(n: u128) -> Keyring {
+					Keyring::Custom(n)
+				}
+
+				/// Return seed string.
+				pub fn to_seed(self) -> String {
+					match self {
+						Keyring::Custom(n) => format!("//{}", n),
+						_ => format!("//{}", self),
+					}
+				}
+
+				/// Iterator over all named test accounts.
+				pub fn iter() -> impl (Iterator<Item = Keyring>) {
+					<Self as strum::IntoEnumIterator>::iter()
+				}
+
+				/// Look up the named account owning `who`.
+				pub fn from_public(who: &Public) -> Option<Keyring> {
+					Self::iter().find(|k| &k.public() == who)
+				}
+
+				/// Look up the named account deriving from `seed`.
+				pub fn from_seed(seed: &str) -> Option<Keyring> {
+					Self::iter().find(|k| k.to_seed() == seed)
+				}
+			}
+
+			impl From<Keyring> for Pair {
+				fn from(k: Keyring) -> Self {
+					k.pair()
+				}
+			}
+		}
+	};
+}
+
+scheme_keyring! {
+	/// Set of test accounts using ed25519 crypto.
+	ed25519
+}
+
+scheme_keyring! {
+	/// Set of test accounts using sr25519 crypto.
+	sr25519
+}
+
+/// `sc-keystore`-compatible (de)serialization for test keyrings.
+///
+/// A node's keystore stores each secret as a file whose entire contents are a JSON string of the
+/// secret URI (e.g. `"//Alice"`). Emitting that exact layout — rather than a bespoke encrypted
+/// envelope — is what lets a test persist a deterministic identity to disk and have a real node
+/// load it, so a validator can be booted from an on-disk key file rather than the in-memory enum.
+pub mod keystore {
+	/// Serialize `suri` into the `sc-keystore` on-disk JSON representation: a JSON string of the
+	/// secret URI.
+	pub fn serialize(suri: &str) -> String {
+		serde_json::to_string(suri).expect("a string always serializes to JSON; qed")
+	}
+
+	/// Parse an `sc-keystore` JSON blob back into its secret URI, or `None` if it is malformed.
+	pub fn deserialize(json: &str) -> Option<String> {
+		serde_json::from_str(json).ok()
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::Keyring;
@@ -134,4 +314,88 @@ mod tests {
 		let got = Keyring::Two.pair().to_raw_vec();
 		assert_eq!(want, got);
 	}
+
+	#[test]
+	fn custom_accounts_work() {
+		let want = Pair::from_string("//42", None).expect("Pair failed").to_raw_vec();
+		let got = Keyring::numeric(42).pair().to_raw_vec();
+		assert_eq!(want, got);
+
+		assert!(Pair::verify(
+			&Keyring::numeric(12345).sign(b"I am account 12345!"),
+			b"I am account 12345!",
+			&Keyring::numeric(12345).public(),
+		));
+
+		// custom accounts are not part of the named-account iterator
+		assert_eq!(Keyring::iter().count(), 8);
+	}
+
+	#[test]
+	fn reverse_lookup_works() {
+		assert_eq!(Keyring::from_public(&Keyring::Alice.public()), Some(Keyring::Alice));
+		assert_eq!(Keyring::from_public(&Keyring::Ferdie.public()), Some(Keyring::Ferdie));
+		assert_eq!(Keyring::from_seed("//Bob"), Some(Keyring::Bob));
+
+		// custom accounts have no named counterpart
+		assert_eq!(Keyring::from_public(&Keyring::numeric(7).public()), None);
+	}
+
+	#[test]
+	fn keccak256_sign_and_recover_works() {
+		let sig = Keyring::Alice.sign_keccak256(b"BEEFY commitment");
+		assert_eq!(Keyring::recover(b"BEEFY commitment", &sig), Some(Keyring::Alice.public()));
+		assert_ne!(Keyring::recover(b"other message", &sig), Some(Keyring::Alice.public()));
+	}
+
+	#[test]
+	fn ed25519_keyring_works() {
+		use super::ed25519::Keyring;
+		use sp_core::ed25519::Pair;
+
+		assert!(Pair::verify(&Keyring::Alice.sign(b"hi"), b"hi", &Keyring::Alice.public()));
+		assert_eq!(Keyring::from_public(&Keyring::Bob.public()), Some(Keyring::Bob));
+
+		let want = Pair::from_string("//Alice", None).expect("Pair failed").to_raw_vec();
+		assert_eq!(Keyring::Alice.pair().to_raw_vec(), want);
+	}
+
+	#[test]
+	fn sr25519_keyring_works() {
+		use super::sr25519::Keyring;
+		use sp_core::sr25519::Pair;
+
+		assert!(Pair::verify(&Keyring::Alice.sign(b"hi"), b"hi", &Keyring::Alice.public()));
+		assert_eq!(Keyring::from_public(&Keyring::Bob.public()), Some(Keyring::Bob));
+	}
+
+	#[test]
+	fn keystore_roundtrip_works() {
+		let json = Keyring::Alice.to_keystore_json();
+		let pair = Keyring::from_keystore_json(&json).expect("load failed");
+		assert_eq!(pair.to_raw_vec(), Keyring::Alice.pair().to_raw_vec());
+
+		// a malformed blob yields no pair
+		assert!(Keyring::from_keystore_json("not json").is_none());
+	}
+
+	#[test]
+	fn boots_from_on_disk_key_file() {
+		use std::io::{Read, Write};
+
+		// write the key out in the same shape a node keystore stores it, ...
+		let path = std::env::temp_dir().join("beefy-test-alice.keystore.json");
+		std::fs::File::create(&path)
+			.expect("create")
+			.write_all(Keyring::Alice.to_keystore_json().as_bytes())
+			.expect("write");
+
+		// ... then boot the pair back from that on-disk file.
+		let mut json = String::new();
+		std::fs::File::open(&path).expect("open").read_to_string(&mut json).expect("read");
+		let _ = std::fs::remove_file(&path);
+
+		let pair = Keyring::from_keystore_json(&json).expect("load failed");
+		assert_eq!(pair.to_raw_vec(), Keyring::Alice.pair().to_raw_vec());
+	}
 }